@@ -7,10 +7,10 @@ use swc_common::Spanned;
 /// ## `T`
 ///
 /// The type of the ast node.
-pub trait Emit<T>
-where
-    T: Spanned,
-{
+///
+/// This is not restricted to [Spanned] types so that lists like
+/// `Vec<Property>` can be emitted directly.
+pub trait Emit<T> {
     fn emit(&mut self, node: &T) -> Result;
 }
 
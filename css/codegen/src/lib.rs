@@ -644,7 +644,14 @@ where
 
     #[emitter]
     fn emit_comma_media_query(&mut self, n: &CommaMediaQuery) -> Result {
-        self.emit_list(&n.queries, ListFormat::CommaDelimited)?;
+        for (idx, query) in n.queries.iter().enumerate() {
+            if idx != 0 {
+                punct!(self, ",");
+                formatting_space!(self);
+            }
+
+            emit!(self, query);
+        }
     }
 
     #[emitter]
@@ -850,3 +857,23 @@ where
         Ok(())
     }
 }
+
+/// Emits a list of declarations without the surrounding braces, as found in
+/// `style` attributes. The output is always a single line.
+impl<W> Emit<Vec<Property>> for CodeGenerator<W>
+where
+    W: CssWriter,
+{
+    fn emit(&mut self, n: &Vec<Property>) -> Result {
+        for (idx, node) in n.iter().enumerate() {
+            if idx != 0 {
+                punct!(self, ";");
+                formatting_space!(self);
+            }
+
+            emit!(self, node);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use swc_common::{input::StringInput, FileName};
+use swc_css_ast::{MediaQuery, Property};
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::{
+    lexer::Lexer,
+    parse_file,
+    parser::{Parser, ParserConfig},
+    Parse,
+};
+
+fn t<T>(src: &str, minify: bool, expected: &str)
+where
+    for<'a> Parser<Lexer<StringInput<'a>>>: Parse<T>,
+    for<'a> CodeGenerator<BasicCssWriter<'a, &'a mut String>>: Emit<T>,
+{
+    testing::run_test2(false, |cm, _handler| {
+        let fm = cm.new_source_file(FileName::Anon, src.to_string());
+        let node: T = parse_file(&fm, ParserConfig { parse_values: true }).unwrap();
+
+        let mut s = String::new();
+        {
+            let wr = BasicCssWriter::new(&mut s, BasicCssWriterConfig { indent: "  " });
+            let mut gen = CodeGenerator::new(wr, CodegenConfig { minify });
+
+            gen.emit(&node).unwrap();
+        }
+
+        assert_eq!(s, expected);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn declaration_list() {
+    t::<Vec<Property>>(
+        "color: red; margin: 0 auto",
+        true,
+        "color:red;margin:0 auto",
+    );
+    t::<Vec<Property>>(
+        "color: red; margin: 0 auto",
+        false,
+        "color: red; margin: 0 auto",
+    );
+}
+
+#[test]
+fn media_query_list() {
+    t::<MediaQuery>("screen, print", true, "screen,print");
+    t::<MediaQuery>("screen,print", false, "screen, print");
+}
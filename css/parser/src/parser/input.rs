@@ -73,7 +73,16 @@ where
         self.cur()?;
 
         if self.peeked.is_none() {
-            self.peeked = Some(self.input.next()?);
+            match self.input.next() {
+                Ok(v) => self.peeked = Some(v),
+                Err(err) => {
+                    if let ErrorKind::Eof = err.kind() {
+                        return Ok(None);
+                    }
+
+                    return Err(err);
+                }
+            }
         }
 
         Ok(self.peeked.as_ref().map(|v| &v.token))
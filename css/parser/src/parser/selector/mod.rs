@@ -183,6 +183,10 @@ where
         let mut subclass_selectors = vec![];
 
         'subclass_selectors: loop {
+            if is!(self, EOF) {
+                break 'subclass_selectors;
+            }
+
             match cur!(self) {
                 Token::Hash { is_id, .. } => {
                     if !*is_id {
@@ -509,3 +513,21 @@ where
         self.parse_compound_selector()
     }
 }
+
+impl<I> Parse<ComplexSelector> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ComplexSelector> {
+        self.parse_complex_selector()
+    }
+}
+
+impl<I> Parse<Vec<ComplexSelector>> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<Vec<ComplexSelector>> {
+        self.parse_selectors()
+    }
+}
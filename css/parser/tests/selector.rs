@@ -0,0 +1,88 @@
+use swc_common::{input::StringInput, BytePos};
+use swc_css_ast::*;
+use swc_css_parser::{
+    error::ErrorKind,
+    lexer::Lexer,
+    parse_str,
+    parser::{PResult, Parser, ParserConfig},
+    Parse,
+};
+
+fn parse<T>(src: &str) -> PResult<T>
+where
+    for<'a> Parser<Lexer<StringInput<'a>>>: Parse<T>,
+{
+    parse_str(
+        src,
+        BytePos(0),
+        BytePos(src.len() as _),
+        ParserConfig {
+            parse_values: false,
+        },
+    )
+}
+
+fn type_names(s: &ComplexSelector) -> Vec<&str> {
+    s.selectors
+        .iter()
+        .map(|c| c.type_selector.as_ref().map_or("", |t| &*t.name.value))
+        .collect()
+}
+
+#[test]
+fn selector_list() {
+    let list: Vec<ComplexSelector> = parse("a, b").unwrap();
+
+    assert_eq!(list.len(), 2);
+    assert_eq!(type_names(&list[0]), vec!["a"]);
+    assert_eq!(type_names(&list[1]), vec!["b"]);
+}
+
+#[test]
+fn compound_selector() {
+    let s: ComplexSelector = parse("a.b").unwrap();
+
+    assert_eq!(s.selectors.len(), 1);
+    assert_eq!(type_names(&s), vec!["a"]);
+    match &s.selectors[0].subclass_selectors[..] {
+        [SubclassSelector::Class(c)] => assert_eq!(&*c.text.value, "b"),
+        v => panic!("expected a single class selector, got {:?}", v),
+    }
+}
+
+#[test]
+fn trailing_pseudo() {
+    for src in &["a:hover", "a::before", "li:first-child", ":not(.foo)"] {
+        let s: ComplexSelector = parse(src).unwrap();
+
+        match s.selectors[0].subclass_selectors.last() {
+            Some(SubclassSelector::Pseudo(..)) => {}
+            v => panic!("{}: expected a pseudo selector, got {:?}", src, v),
+        }
+    }
+}
+
+#[test]
+fn combinators() {
+    let s: ComplexSelector = parse("ul > li a ").unwrap();
+
+    assert_eq!(type_names(&s), vec!["ul", "li", "a"]);
+    assert_eq!(s.selectors[1].combinator, Some(SelectorCombinator::Child));
+    // Descendant combinators are implied by whitespace.
+    assert_eq!(s.selectors[2].combinator, None);
+}
+
+#[test]
+fn trailing_combinator() {
+    for src in &["a >", "a + ", "a ~"] {
+        let err = parse::<ComplexSelector>(src).unwrap_err();
+
+        // The selector after the combinator is missing.
+        assert!(
+            matches!(err.kind(), ErrorKind::Eof),
+            "{}: {:?}",
+            src,
+            err.kind()
+        );
+    }
+}
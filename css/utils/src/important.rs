@@ -0,0 +1,122 @@
+//! Removal of `!important`.
+
+use swc_css_ast::*;
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+pub struct ImportantRemover {
+    strip_flags: bool,
+}
+
+impl VisitMut for ImportantRemover {
+    fn visit_mut_keyframe_block_rule(&mut self, n: &mut KeyframeBlockRule) {
+        if let KeyframeBlockRule::Decl(block) = n {
+            block.properties.retain(|p| p.important.is_none());
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_property(&mut self, n: &mut Property) {
+        n.visit_mut_children_with(self);
+
+        if self.strip_flags {
+            n.important = None;
+        }
+    }
+}
+
+/// Drops declarations marked `!important` inside `@keyframes`.
+///
+/// Browsers ignore these declarations entirely, so removing them does not
+/// change how the stylesheet is applied.
+pub fn remove_important_in_keyframes<N>(node: &mut N)
+where
+    N: VisitMutWith<ImportantRemover>,
+{
+    node.visit_mut_with(&mut ImportantRemover { strip_flags: false });
+}
+
+/// Drops declarations marked `!important` inside `@keyframes` like
+/// [remove_important_in_keyframes], and removes `!important` from all other
+/// declarations.
+///
+/// Unlike [remove_important_in_keyframes], this does not preserve behavior:
+/// declarations which used to win the cascade because of `!important` may
+/// now be overridden.
+pub fn remove_important<N>(node: &mut N)
+where
+    N: VisitMutWith<ImportantRemover>,
+{
+    node.visit_mut_with(&mut ImportantRemover { strip_flags: true });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::{BytePos, DUMMY_SP};
+    use swc_css_parser::{parse_str, parser::ParserConfig};
+    use swc_css_visit::{Node, Visit, VisitWith};
+
+    #[derive(Default)]
+    struct ImportantCollector(Vec<(String, bool)>);
+
+    impl Visit for ImportantCollector {
+        fn visit_property(&mut self, n: &Property, _: &dyn Node) {
+            self.0
+                .push((n.name.value.to_string(), n.important.is_some()));
+        }
+    }
+
+    fn t(src: &str, op: fn(&mut Stylesheet), expected: &[(&str, bool)]) {
+        let mut ss: Stylesheet = parse_str(
+            src,
+            BytePos(0),
+            BytePos(src.len() as _),
+            ParserConfig { parse_values: true },
+        )
+        .unwrap();
+
+        op(&mut ss);
+
+        let mut v = ImportantCollector::default();
+        ss.visit_with(&Invalid { span: DUMMY_SP }, &mut v);
+
+        let actual: Vec<_> = v.0.iter().map(|(n, i)| (&**n, *i)).collect();
+        assert_eq!(actual, expected);
+    }
+
+    const SRC: &str = "a { color: red !important }
+        @keyframes foo {
+            from { top: 0 !important; left: 0 }
+            to { top: 10px }
+        }
+        @media screen { b { margin: 0 !important } }";
+
+    #[test]
+    fn keyframes() {
+        t(
+            SRC,
+            remove_important_in_keyframes,
+            &[
+                ("color", true),
+                ("left", false),
+                ("top", false),
+                ("margin", true),
+            ],
+        );
+    }
+
+    #[test]
+    fn everywhere() {
+        t(
+            SRC,
+            remove_important,
+            &[
+                ("color", false),
+                ("left", false),
+                ("top", false),
+                ("margin", false),
+            ],
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use swc_css_ast::*;
 use swc_css_visit::{VisitMut, VisitMutWith};
 
+pub mod important;
+pub mod properties;
+pub mod selector;
+pub mod shorthands;
+pub mod vendor_prefix;
+
 pub struct TextReplacer<'a> {
     from: &'a str,
     to: &'a str,
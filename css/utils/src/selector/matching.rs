@@ -0,0 +1,471 @@
+use super::{Component, Compound, Selector};
+use std::iter::successors;
+use swc_css_ast::*;
+
+/// An element of a document which selectors can be matched against.
+///
+/// This is usually implemented for a cheap handle to a node, like a reference
+/// or an index into an arena.
+pub trait Element: Sized + Clone {
+    fn parent_element(&self) -> Option<Self>;
+
+    fn prev_sibling_element(&self) -> Option<Self>;
+
+    fn next_sibling_element(&self) -> Option<Self>;
+
+    /// `name` is the type selector as written, so implementations for HTML
+    /// should compare ASCII case-insensitively.
+    fn has_local_name(&self, name: &str) -> bool;
+
+    fn id(&self) -> Option<&str>;
+
+    fn has_class(&self, name: &str) -> bool;
+
+    fn attr(&self, name: &str) -> Option<&str>;
+}
+
+/// Result of matching a selector against an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchResult {
+    Yes,
+    No,
+    /// The selector may or may not match depending on things which are not
+    /// part of the document, like `:hover`, or on selectors which are not
+    /// supported. Callers removing unused rules should treat this as
+    /// [MatchResult::Yes].
+    Unknown,
+}
+
+impl MatchResult {
+    /// Returns `true` unless the selector definitely does not match.
+    pub fn may_match(self) -> bool {
+        self != MatchResult::No
+    }
+
+    fn not(self) -> Self {
+        match self {
+            MatchResult::Yes => MatchResult::No,
+            MatchResult::No => MatchResult::Yes,
+            MatchResult::Unknown => MatchResult::Unknown,
+        }
+    }
+}
+
+impl From<bool> for MatchResult {
+    fn from(v: bool) -> Self {
+        if v {
+            MatchResult::Yes
+        } else {
+            MatchResult::No
+        }
+    }
+}
+
+/// `Yes` if all of `results` are `Yes`, `No` if any of them is `No`.
+fn all(results: impl Iterator<Item = MatchResult>) -> MatchResult {
+    let mut res = MatchResult::Yes;
+    for r in results {
+        match r {
+            MatchResult::Yes => {}
+            MatchResult::No => return MatchResult::No,
+            MatchResult::Unknown => res = MatchResult::Unknown,
+        }
+    }
+    res
+}
+
+/// `Yes` if any of `results` is `Yes`, `No` if all of them are `No`.
+fn any(results: impl Iterator<Item = MatchResult>) -> MatchResult {
+    let mut res = MatchResult::No;
+    for r in results {
+        match r {
+            MatchResult::Yes => return MatchResult::Yes,
+            MatchResult::No => {}
+            MatchResult::Unknown => res = MatchResult::Unknown,
+        }
+    }
+    res
+}
+
+/// Matches `element` against each of `selectors`, returning the most
+/// positive result.
+pub fn matches_any<E>(selectors: &[Selector], element: &E) -> MatchResult
+where
+    E: Element,
+{
+    any(selectors.iter().map(|s| s.matches(element)))
+}
+
+impl Selector {
+    /// Matches `element` against this selector.
+    ///
+    /// Supported are type, universal, id, class and attribute selectors, all
+    /// combinators, `:not()`, `:is()`, `:where()`, `:matches()`, `:root`,
+    /// `:first-child`, `:last-child` and `:only-child`. Namespace prefixes are
+    /// ignored. Everything else, including pseudo elements, the nesting prefix
+    /// (`&`) and state-dependent pseudo classes like `:hover`, results in
+    /// [MatchResult::Unknown] unless another part of the selector does not
+    /// match.
+    pub fn matches<E>(&self, element: &E) -> MatchResult
+    where
+        E: Element,
+    {
+        matches_compounds(&self.compounds, element)
+    }
+}
+
+fn matches_compounds<E>(compounds: &[Compound], element: &E) -> MatchResult
+where
+    E: Element,
+{
+    let (last, rest) = match compounds.split_last() {
+        Some(v) => v,
+        None => return MatchResult::No,
+    };
+
+    let res = all(last
+        .components
+        .iter()
+        .map(|c| matches_component(c, element)));
+    if res == MatchResult::No || rest.is_empty() {
+        return res;
+    }
+
+    let relation = match last.combinator {
+        SelectorCombinator::Child => element
+            .parent_element()
+            .map_or(MatchResult::No, |parent| matches_compounds(rest, &parent)),
+
+        SelectorCombinator::Descendant => {
+            any(successors(element.parent_element(), E::parent_element)
+                .map(|el| matches_compounds(rest, &el)))
+        }
+
+        SelectorCombinator::NextSibling => element
+            .prev_sibling_element()
+            .map_or(MatchResult::No, |sibling| matches_compounds(rest, &sibling)),
+
+        SelectorCombinator::LaterSibling => any(successors(
+            element.prev_sibling_element(),
+            E::prev_sibling_element,
+        )
+        .map(|el| matches_compounds(rest, &el))),
+    };
+
+    match (res, relation) {
+        (_, MatchResult::No) => MatchResult::No,
+        (MatchResult::Yes, MatchResult::Yes) => MatchResult::Yes,
+        _ => MatchResult::Unknown,
+    }
+}
+
+fn matches_component<E>(n: &Component, element: &E) -> MatchResult
+where
+    E: Element,
+{
+    match n {
+        Component::Type(name) => MatchResult::from(element.has_local_name(name)),
+        Component::Id(id) => MatchResult::from(element.id() == Some(&**id)),
+        Component::Class(class) => MatchResult::from(element.has_class(class)),
+        Component::Attr {
+            name,
+            value,
+            case_insensitive,
+        } => MatchResult::from(match element.attr(name) {
+            Some(actual) => match value {
+                Some((op, expected)) => {
+                    matches_attr_value(*op, actual, expected, *case_insensitive)
+                }
+                None => true,
+            },
+            None => false,
+        }),
+        Component::Not(args) => matches_any(args, element).not(),
+        Component::Is(args) | Component::LegacyAny(args) | Component::Where(args) => {
+            matches_any(args, element)
+        }
+        Component::Root => MatchResult::from(element.parent_element().is_none()),
+        Component::FirstChild => MatchResult::from(element.prev_sibling_element().is_none()),
+        Component::LastChild => MatchResult::from(element.next_sibling_element().is_none()),
+        Component::OnlyChild => MatchResult::from(
+            element.prev_sibling_element().is_none() && element.next_sibling_element().is_none(),
+        ),
+        Component::Unknown(..) => MatchResult::Unknown,
+    }
+}
+
+/// The `i` modifier makes the comparison ASCII case-insensitive, as required
+/// by https://www.w3.org/TR/selectors-4/#attribute-case
+fn matches_attr_value(
+    op: AttrSelectorOp,
+    actual: &str,
+    expected: &str,
+    case_insensitive: bool,
+) -> bool {
+    let eq = |a: &[u8], b: &[u8]| {
+        if case_insensitive {
+            a.eq_ignore_ascii_case(b)
+        } else {
+            a == b
+        }
+    };
+
+    let (a, e) = (actual.as_bytes(), expected.as_bytes());
+
+    match op {
+        AttrSelectorOp::Equals => eq(a, e),
+        AttrSelectorOp::Tilde => {
+            !e.is_empty()
+                && !expected.contains(char::is_whitespace)
+                && actual.split_whitespace().any(|v| eq(v.as_bytes(), e))
+        }
+        AttrSelectorOp::Bar => {
+            eq(a, e) || (a.len() > e.len() && a[e.len()] == b'-' && eq(&a[..e.len()], e))
+        }
+        AttrSelectorOp::Caret => !e.is_empty() && a.len() >= e.len() && eq(&a[..e.len()], e),
+        AttrSelectorOp::Dollar => {
+            !e.is_empty() && a.len() >= e.len() && eq(&a[a.len() - e.len()..], e)
+        }
+        AttrSelectorOp::Asterisk => !e.is_empty() && a.windows(e.len()).any(|w| eq(w, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::BytePos;
+    use swc_css_parser::{parse_str, parser::ParserConfig};
+
+    struct Node {
+        name: &'static str,
+        attrs: Vec<(&'static str, &'static str)>,
+        children: Vec<Node>,
+    }
+
+    fn node(
+        name: &'static str,
+        attrs: Vec<(&'static str, &'static str)>,
+        children: Vec<Node>,
+    ) -> Node {
+        Node {
+            name,
+            attrs,
+            children,
+        }
+    }
+
+    /// A node together with the path of child indices leading to it.
+    #[derive(Clone)]
+    struct El<'a> {
+        root: &'a Node,
+        path: Vec<usize>,
+    }
+
+    impl<'a> El<'a> {
+        fn node(&self) -> &'a Node {
+            let mut n = self.root;
+            for &idx in &self.path {
+                n = &n.children[idx];
+            }
+            n
+        }
+
+        fn sibling(&self, offset: isize) -> Option<Self> {
+            let (&last, parent_path) = self.path.split_last()?;
+            let idx = last as isize + offset;
+            let parent = El {
+                root: self.root,
+                path: parent_path.to_vec(),
+            };
+            if idx < 0 || idx as usize >= parent.node().children.len() {
+                return None;
+            }
+            let mut path = parent.path;
+            path.push(idx as usize);
+            Some(El {
+                root: self.root,
+                path,
+            })
+        }
+    }
+
+    impl Element for El<'_> {
+        fn parent_element(&self) -> Option<Self> {
+            let (_, parent) = self.path.split_last()?;
+            Some(El {
+                root: self.root,
+                path: parent.to_vec(),
+            })
+        }
+
+        fn prev_sibling_element(&self) -> Option<Self> {
+            self.sibling(-1)
+        }
+
+        fn next_sibling_element(&self) -> Option<Self> {
+            self.sibling(1)
+        }
+
+        fn has_local_name(&self, name: &str) -> bool {
+            self.node().name.eq_ignore_ascii_case(name)
+        }
+
+        fn id(&self) -> Option<&str> {
+            self.attr("id")
+        }
+
+        fn has_class(&self, name: &str) -> bool {
+            self.attr("class")
+                .map_or(false, |v| v.split_whitespace().any(|c| c == name))
+        }
+
+        fn attr(&self, name: &str) -> Option<&str> {
+            self.node()
+                .attrs
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| *v)
+        }
+    }
+
+    fn selector(src: &str) -> Selector {
+        let selector: ComplexSelector = parse_str(
+            src,
+            BytePos(0),
+            BytePos(src.len() as _),
+            ParserConfig {
+                parse_values: false,
+            },
+        )
+        .unwrap();
+
+        Selector::new(&selector)
+    }
+
+    /// ```html
+    /// <html>
+    ///   <body>
+    ///     <ul id="nav">
+    ///       <li class="item first"><a href="https://example.com" title="ä">
+    ///       <li class="item active" lang="en-US">
+    ///     </ul>
+    ///   </body>
+    /// </html>
+    /// ```
+    fn doc() -> Node {
+        node(
+            "html",
+            vec![],
+            vec![node(
+                "body",
+                vec![],
+                vec![node(
+                    "ul",
+                    vec![("id", "nav")],
+                    vec![
+                        node(
+                            "li",
+                            vec![("class", "item first")],
+                            vec![node(
+                                "a",
+                                vec![("href", "https://example.com"), ("title", "\u{e4}")],
+                                vec![],
+                            )],
+                        ),
+                        node(
+                            "li",
+                            vec![("class", "item active"), ("lang", "en-US")],
+                            vec![],
+                        ),
+                    ],
+                )],
+            )],
+        )
+    }
+
+    fn t(src: &str, path: &[usize], expected: impl Into<MatchResult>) {
+        let root = doc();
+        let el = El {
+            root: &root,
+            path: path.to_vec(),
+        };
+
+        assert_eq!(selector(src).matches(&el), expected.into(), "{}", src);
+    }
+
+    const UL: &[usize] = &[0, 0];
+    const FIRST_LI: &[usize] = &[0, 0, 0];
+    const SECOND_LI: &[usize] = &[0, 0, 1];
+    const A: &[usize] = &[0, 0, 0, 0];
+
+    #[test]
+    fn simple() {
+        t("*", A, true);
+        t("LI", FIRST_LI, true);
+        t("li", UL, false);
+        t("#nav", UL, true);
+        t(".active", SECOND_LI, true);
+        t(".active", FIRST_LI, false);
+        t("li.item.first", FIRST_LI, true);
+    }
+
+    #[test]
+    fn attributes() {
+        t("[href]", A, true);
+        t("[href^='https:']", A, true);
+        t("[href$='.org']", A, false);
+        t("[href*=example]", A, true);
+        t("[class~=active]", SECOND_LI, true);
+        t("[lang|=en]", SECOND_LI, true);
+        t("[lang='EN-us' i]", SECOND_LI, true);
+        t("[lang='EN-us']", SECOND_LI, false);
+        t("[lang^='EN' i]", SECOND_LI, true);
+        t("[lang|='EN' i]", SECOND_LI, true);
+        // The `i` modifier only folds ASCII letters.
+        t("[title='\u{c4}' i]", A, false);
+        t("[title='\u{e4}' i]", A, true);
+    }
+
+    #[test]
+    fn combinators() {
+        t("html a", A, true);
+        t("body > a", A, false);
+        t("ul > li > a", A, true);
+        t(".first + li", SECOND_LI, true);
+        t(".first ~ .active", SECOND_LI, true);
+        t(".active ~ .first", FIRST_LI, false);
+        t("body li.item a", A, true);
+    }
+
+    #[test]
+    fn pseudo_classes() {
+        t(":root", &[], true);
+        t("li:first-child", FIRST_LI, true);
+        t("li:last-child", FIRST_LI, false);
+        t("a:only-child", A, true);
+        t("li:not(.active)", FIRST_LI, true);
+        t("li:not(.active)", SECOND_LI, false);
+        t(":is(ul, ol) > li", SECOND_LI, true);
+        t(":-webkit-any(ul, ol) > li", SECOND_LI, true);
+    }
+
+    #[test]
+    fn unknown() {
+        use MatchResult::Unknown;
+
+        t("a:hover", A, Unknown);
+        t("a::before", A, Unknown);
+        t("li:nth-child(2)", SECOND_LI, Unknown);
+        t("a:not(:hover)", A, Unknown);
+        t("li:hover > a", A, Unknown);
+        t("a:is(:focus, [href])", A, true);
+        t("a:host", A, Unknown);
+
+        // Parts which are known not to match decide the result.
+        t("li:hover", A, false);
+        t("ol:hover > a", A, false);
+        t("a:not(:hover, [href])", A, false);
+        t("&.first", SECOND_LI, false);
+        t("&.first", FIRST_LI, Unknown);
+    }
+}
@@ -0,0 +1,182 @@
+//! Selector specificity and matching.
+
+pub use self::{
+    matching::{matches_any, Element, MatchResult},
+    specificity::{specificity, Specificity},
+};
+use std::ops::Add;
+use swc_atoms::JsWord;
+use swc_css_ast::*;
+use swc_css_parser::{parse_tokens, parser::ParserConfig};
+
+mod matching;
+mod specificity;
+
+/// A selector prepared for matching.
+///
+/// Arguments of pseudo classes like `:not()` are parsed once when the
+/// selector is created, so it can be matched against many elements cheaply.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    compounds: Vec<Compound>,
+    specificity: Specificity,
+}
+
+#[derive(Debug, Clone)]
+struct Compound {
+    /// How this compound relates to the previous one. Unused for the first
+    /// compound.
+    combinator: SelectorCombinator,
+    components: Vec<Component>,
+}
+
+#[derive(Debug, Clone)]
+enum Component {
+    Type(JsWord),
+    Id(JsWord),
+    Class(JsWord),
+    Attr {
+        name: JsWord,
+        value: Option<(AttrSelectorOp, JsWord)>,
+        case_insensitive: bool,
+    },
+    Not(Vec<Selector>),
+    /// `:is()` and its legacy aliases.
+    Is(Vec<Selector>),
+    /// `:-webkit-any()` and `:-moz-any()`.
+    LegacyAny(Vec<Selector>),
+    Where(Vec<Selector>),
+    Root,
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    /// Anything whose match can't be decided from the document alone, like
+    /// `:hover`, pseudo elements or the nesting prefix (`&`).
+    Unknown(Specificity),
+}
+
+impl Selector {
+    pub fn new(selector: &ComplexSelector) -> Self {
+        let compounds: Vec<_> = selector.selectors.iter().map(compile_compound).collect();
+        let specificity = compounds
+            .iter()
+            .flat_map(|c| &c.components)
+            .map(Component::specificity)
+            .fold(Specificity::default(), Add::add);
+
+        Selector {
+            compounds,
+            specificity,
+        }
+    }
+
+    pub fn specificity(&self) -> Specificity {
+        self.specificity
+    }
+}
+
+fn compile_compound(n: &CompoundSelector) -> Compound {
+    let mut components = vec![];
+
+    if n.has_nest_prefix {
+        components.push(Component::Unknown(Specificity::default()));
+    }
+
+    if let Some(type_selector) = &n.type_selector {
+        if &*type_selector.name.value != "*" {
+            components.push(Component::Type(type_selector.name.value.clone()));
+        }
+    }
+
+    for sel in &n.subclass_selectors {
+        components.push(match sel {
+            SubclassSelector::Id(id) => Component::Id(id.text.value.clone()),
+            SubclassSelector::Class(class) => Component::Class(class.text.value.clone()),
+            SubclassSelector::Attr(attr) => Component::Attr {
+                name: attr.name.name.value.clone(),
+                value: attr.op.zip(attr.value.as_ref().map(|v| v.value.clone())),
+                case_insensitive: matches!(attr.modifier, Some('i') | Some('I')),
+            },
+            SubclassSelector::Pseudo(pseudo) => compile_pseudo(pseudo),
+            SubclassSelector::At(..) => Component::Unknown(Specificity::default()),
+        });
+    }
+
+    Compound {
+        combinator: n.combinator.unwrap_or(SelectorCombinator::Descendant),
+        components,
+    }
+}
+
+fn compile_pseudo(p: &PseudoSelector) -> Component {
+    let name = p.name.value.to_ascii_lowercase();
+    let args = || parse_selector_list(&p.args);
+    let args_specificity = || args().map(|a| max_specificity(&a)).unwrap_or_default();
+
+    let pseudo_element = Specificity {
+        types: 1,
+        ..Default::default()
+    };
+    let pseudo_class = Specificity {
+        classes: 1,
+        ..Default::default()
+    };
+
+    if p.is_element || is_legacy_pseudo_element(&name) {
+        return match &*name {
+            "slotted" => Component::Unknown(pseudo_element + args_specificity()),
+            _ => Component::Unknown(pseudo_element),
+        };
+    }
+
+    match &*name {
+        "not" => args().map_or(Component::Unknown(Specificity::default()), Component::Not),
+        "is" | "matches" | "any" => {
+            args().map_or(Component::Unknown(Specificity::default()), Component::Is)
+        }
+        // Unlike `:is()`, the prefixed versions count as a single pseudo class.
+        "-webkit-any" | "-moz-any" => {
+            args().map_or(Component::Unknown(pseudo_class), Component::LegacyAny)
+        }
+        "where" => args().map_or(Component::Unknown(Specificity::default()), Component::Where),
+        // Relative selectors are not supported, so only the specificity is known.
+        "has" => Component::Unknown(args_specificity()),
+        // Shadow DOM is not supported.
+        "host" | "host-context" => Component::Unknown(pseudo_class + args_specificity()),
+        "root" => Component::Root,
+        "first-child" => Component::FirstChild,
+        "last-child" => Component::LastChild,
+        "only-child" => Component::OnlyChild,
+        _ => Component::Unknown(pseudo_class),
+    }
+}
+
+/// Pseudo elements which may be written with a single colon.
+fn is_legacy_pseudo_element(name: &str) -> bool {
+    matches!(name, "before" | "after" | "first-line" | "first-letter")
+}
+
+fn max_specificity(selectors: &[Selector]) -> Specificity {
+    selectors
+        .iter()
+        .map(Selector::specificity)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Parses the arguments of pseudo classes like `:not()` as a selector list.
+fn parse_selector_list(args: &Tokens) -> Option<Vec<Selector>> {
+    if args.tokens.is_empty() {
+        return None;
+    }
+
+    let selectors: Vec<ComplexSelector> = parse_tokens(
+        args,
+        ParserConfig {
+            parse_values: false,
+        },
+    )
+    .ok()?;
+
+    Some(selectors.iter().map(Selector::new).collect())
+}
@@ -0,0 +1,150 @@
+use super::{max_specificity, Component, Selector};
+use std::ops::Add;
+use swc_css_ast::*;
+
+/// Specificity of a selector, as defined by
+/// https://www.w3.org/TR/selectors-4/#specificity-rules
+///
+/// Specificities are compared by `ids` first, then `classes`, then `types`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity {
+    /// Number of id selectors.
+    pub ids: u32,
+    /// Number of class selectors, attribute selectors and pseudo classes.
+    pub classes: u32,
+    /// Number of type selectors and pseudo elements.
+    pub types: u32,
+}
+
+impl Add for Specificity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Specificity {
+            ids: self.ids + rhs.ids,
+            classes: self.classes + rhs.classes,
+            types: self.types + rhs.types,
+        }
+    }
+}
+
+/// Calculates the specificity of `selector`.
+///
+/// `:is()`, `:not()`, `:has()` and `:matches()` take the specificity of their
+/// most specific argument, and `:where()` is always zero. `:host()`,
+/// `:host-context()` and `::slotted()` add the specificity of their argument
+/// to that of the pseudo class or element. The nesting prefix
+/// (`&`) and `of S` arguments of `:nth-child()` are not resolved and count as
+/// nothing.
+///
+/// To also match the selector, create a [Selector] and use
+/// [Selector::specificity] instead.
+pub fn specificity(selector: &ComplexSelector) -> Specificity {
+    Selector::new(selector).specificity()
+}
+
+impl Component {
+    pub(super) fn specificity(&self) -> Specificity {
+        match self {
+            Component::Id(..) => Specificity {
+                ids: 1,
+                ..Default::default()
+            },
+            Component::Class(..)
+            | Component::Attr { .. }
+            | Component::LegacyAny(..)
+            | Component::Root
+            | Component::FirstChild
+            | Component::LastChild
+            | Component::OnlyChild => Specificity {
+                classes: 1,
+                ..Default::default()
+            },
+            Component::Type(..) => Specificity {
+                types: 1,
+                ..Default::default()
+            },
+            Component::Not(args) | Component::Is(args) => max_specificity(args),
+            Component::Where(..) => Specificity::default(),
+            Component::Unknown(s) => *s,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::BytePos;
+    use swc_css_parser::{parse_str, parser::ParserConfig};
+
+    fn t(src: &str, expected: (u32, u32, u32)) {
+        let selector: ComplexSelector = parse_str(
+            src,
+            BytePos(0),
+            BytePos(src.len() as _),
+            ParserConfig {
+                parse_values: false,
+            },
+        )
+        .unwrap();
+
+        let (ids, classes, types) = expected;
+        assert_eq!(
+            specificity(&selector),
+            Specificity {
+                ids,
+                classes,
+                types
+            },
+            "{}",
+            src
+        );
+    }
+
+    #[test]
+    fn simple() {
+        t("*", (0, 0, 0));
+        t("a", (0, 0, 1));
+        t(".foo", (0, 1, 0));
+        t("#foo", (1, 0, 0));
+        t("[type=text]", (0, 1, 0));
+        t("a:hover", (0, 1, 1));
+        t("a::before", (0, 0, 2));
+        t("a:after", (0, 0, 2));
+    }
+
+    #[test]
+    fn complex() {
+        t("ul li.active > a", (0, 1, 3));
+        t("#nav .item + .item", (1, 2, 0));
+    }
+
+    #[test]
+    fn functional_pseudo_classes() {
+        t(":not(#foo)", (1, 0, 0));
+        t("a:is(.foo, #bar)", (1, 0, 1));
+        t("a:where(#foo)", (0, 0, 1));
+        t(":-webkit-any(#foo, .bar)", (0, 1, 0));
+        t(":-moz-any(#foo)", (0, 1, 0));
+        t(":host", (0, 1, 0));
+        t(":host(#a)", (1, 1, 0));
+        t(":host-context(.dark)", (0, 2, 0));
+        t("::slotted(#a)", (1, 0, 1));
+        t("::slotted(span)", (0, 0, 2));
+    }
+
+    #[test]
+    fn ordering() {
+        let a = Specificity {
+            ids: 1,
+            ..Default::default()
+        };
+        let b = Specificity {
+            classes: 10,
+            types: 10,
+            ..Default::default()
+        };
+
+        assert!(a > b);
+    }
+}
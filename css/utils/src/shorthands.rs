@@ -0,0 +1,548 @@
+/// Shorthand properties and their longhands, sorted by shorthand so they can
+/// be binary searched.
+static SHORTHANDS: &[(&str, &[&str])] = &[
+    (
+        "animation",
+        &[
+            "animation-name",
+            "animation-duration",
+            "animation-timing-function",
+            "animation-delay",
+            "animation-iteration-count",
+            "animation-direction",
+            "animation-fill-mode",
+            "animation-play-state",
+        ],
+    ),
+    (
+        "background",
+        &[
+            "background-image",
+            "background-position",
+            "background-position-x",
+            "background-position-y",
+            "background-size",
+            "background-repeat",
+            "background-origin",
+            "background-clip",
+            "background-attachment",
+            "background-color",
+        ],
+    ),
+    (
+        "background-position",
+        &["background-position-x", "background-position-y"],
+    ),
+    (
+        "border",
+        &[
+            "border-top",
+            "border-right",
+            "border-bottom",
+            "border-left",
+            "border-width",
+            "border-style",
+            "border-color",
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+            "border-image",
+            "border-image-source",
+            "border-image-slice",
+            "border-image-width",
+            "border-image-outset",
+            "border-image-repeat",
+        ],
+    ),
+    (
+        "border-block",
+        &[
+            "border-block-start",
+            "border-block-end",
+            "border-block-width",
+            "border-block-style",
+            "border-block-color",
+            "border-block-start-width",
+            "border-block-start-style",
+            "border-block-start-color",
+            "border-block-end-width",
+            "border-block-end-style",
+            "border-block-end-color",
+        ],
+    ),
+    (
+        "border-block-color",
+        &["border-block-start-color", "border-block-end-color"],
+    ),
+    (
+        "border-block-end",
+        &[
+            "border-block-end-width",
+            "border-block-end-style",
+            "border-block-end-color",
+        ],
+    ),
+    (
+        "border-block-start",
+        &[
+            "border-block-start-width",
+            "border-block-start-style",
+            "border-block-start-color",
+        ],
+    ),
+    (
+        "border-block-style",
+        &["border-block-start-style", "border-block-end-style"],
+    ),
+    (
+        "border-block-width",
+        &["border-block-start-width", "border-block-end-width"],
+    ),
+    (
+        "border-bottom",
+        &[
+            "border-bottom-width",
+            "border-bottom-style",
+            "border-bottom-color",
+        ],
+    ),
+    (
+        "border-color",
+        &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+    ),
+    (
+        "border-image",
+        &[
+            "border-image-source",
+            "border-image-slice",
+            "border-image-width",
+            "border-image-outset",
+            "border-image-repeat",
+        ],
+    ),
+    (
+        "border-inline",
+        &[
+            "border-inline-start",
+            "border-inline-end",
+            "border-inline-width",
+            "border-inline-style",
+            "border-inline-color",
+            "border-inline-start-width",
+            "border-inline-start-style",
+            "border-inline-start-color",
+            "border-inline-end-width",
+            "border-inline-end-style",
+            "border-inline-end-color",
+        ],
+    ),
+    (
+        "border-inline-color",
+        &["border-inline-start-color", "border-inline-end-color"],
+    ),
+    (
+        "border-inline-end",
+        &[
+            "border-inline-end-width",
+            "border-inline-end-style",
+            "border-inline-end-color",
+        ],
+    ),
+    (
+        "border-inline-start",
+        &[
+            "border-inline-start-width",
+            "border-inline-start-style",
+            "border-inline-start-color",
+        ],
+    ),
+    (
+        "border-inline-style",
+        &["border-inline-start-style", "border-inline-end-style"],
+    ),
+    (
+        "border-inline-width",
+        &["border-inline-start-width", "border-inline-end-width"],
+    ),
+    (
+        "border-left",
+        &[
+            "border-left-width",
+            "border-left-style",
+            "border-left-color",
+        ],
+    ),
+    (
+        "border-radius",
+        &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+    ),
+    (
+        "border-right",
+        &[
+            "border-right-width",
+            "border-right-style",
+            "border-right-color",
+        ],
+    ),
+    (
+        "border-style",
+        &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+    ),
+    (
+        "border-top",
+        &["border-top-width", "border-top-style", "border-top-color"],
+    ),
+    (
+        "border-width",
+        &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+    ),
+    (
+        "column-rule",
+        &[
+            "column-rule-width",
+            "column-rule-style",
+            "column-rule-color",
+        ],
+    ),
+    ("columns", &["column-width", "column-count"]),
+    (
+        "contain-intrinsic-size",
+        &["contain-intrinsic-width", "contain-intrinsic-height"],
+    ),
+    ("container", &["container-name", "container-type"]),
+    ("flex", &["flex-grow", "flex-shrink", "flex-basis"]),
+    ("flex-flow", &["flex-direction", "flex-wrap"]),
+    (
+        "font",
+        &[
+            "font-style",
+            "font-variant",
+            "font-variant-caps",
+            "font-variant-ligatures",
+            "font-variant-numeric",
+            "font-variant-east-asian",
+            "font-variant-alternates",
+            "font-variant-position",
+            "font-weight",
+            "font-stretch",
+            "font-size",
+            "line-height",
+            "font-family",
+            "font-size-adjust",
+            "font-kerning",
+            "font-language-override",
+            "font-optical-sizing",
+            "font-variation-settings",
+            "font-feature-settings",
+        ],
+    ),
+    (
+        "font-synthesis",
+        &[
+            "font-synthesis-weight",
+            "font-synthesis-style",
+            "font-synthesis-small-caps",
+            "font-synthesis-position",
+        ],
+    ),
+    (
+        "font-variant",
+        &[
+            "font-variant-caps",
+            "font-variant-ligatures",
+            "font-variant-numeric",
+            "font-variant-east-asian",
+            "font-variant-alternates",
+            "font-variant-position",
+        ],
+    ),
+    (
+        "gap",
+        &["row-gap", "column-gap", "grid-row-gap", "grid-column-gap"],
+    ),
+    (
+        "grid",
+        &[
+            "grid-template",
+            "grid-template-rows",
+            "grid-template-columns",
+            "grid-template-areas",
+            "grid-auto-rows",
+            "grid-auto-columns",
+            "grid-auto-flow",
+        ],
+    ),
+    (
+        "grid-area",
+        &[
+            "grid-row",
+            "grid-column",
+            "grid-row-start",
+            "grid-column-start",
+            "grid-row-end",
+            "grid-column-end",
+        ],
+    ),
+    ("grid-column", &["grid-column-start", "grid-column-end"]),
+    (
+        "grid-gap",
+        &["row-gap", "column-gap", "grid-row-gap", "grid-column-gap"],
+    ),
+    ("grid-row", &["grid-row-start", "grid-row-end"]),
+    (
+        "grid-template",
+        &[
+            "grid-template-rows",
+            "grid-template-columns",
+            "grid-template-areas",
+        ],
+    ),
+    ("inset", &["top", "right", "bottom", "left"]),
+    ("inset-block", &["inset-block-start", "inset-block-end"]),
+    ("inset-inline", &["inset-inline-start", "inset-inline-end"]),
+    (
+        "list-style",
+        &["list-style-type", "list-style-position", "list-style-image"],
+    ),
+    (
+        "margin",
+        &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+    ),
+    ("margin-block", &["margin-block-start", "margin-block-end"]),
+    (
+        "margin-inline",
+        &["margin-inline-start", "margin-inline-end"],
+    ),
+    ("marker", &["marker-start", "marker-mid", "marker-end"]),
+    (
+        "mask",
+        &[
+            "mask-image",
+            "mask-mode",
+            "mask-position",
+            "mask-size",
+            "mask-repeat",
+            "mask-origin",
+            "mask-clip",
+            "mask-composite",
+            "mask-border",
+            "mask-border-source",
+            "mask-border-slice",
+            "mask-border-width",
+            "mask-border-outset",
+            "mask-border-repeat",
+            "mask-border-mode",
+        ],
+    ),
+    (
+        "mask-border",
+        &[
+            "mask-border-source",
+            "mask-border-slice",
+            "mask-border-width",
+            "mask-border-outset",
+            "mask-border-repeat",
+            "mask-border-mode",
+        ],
+    ),
+    (
+        "offset",
+        &[
+            "offset-position",
+            "offset-path",
+            "offset-distance",
+            "offset-rotate",
+            "offset-anchor",
+        ],
+    ),
+    (
+        "outline",
+        &["outline-color", "outline-style", "outline-width"],
+    ),
+    ("overflow", &["overflow-x", "overflow-y"]),
+    (
+        "overscroll-behavior",
+        &["overscroll-behavior-x", "overscroll-behavior-y"],
+    ),
+    (
+        "padding",
+        &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+    ),
+    (
+        "padding-block",
+        &["padding-block-start", "padding-block-end"],
+    ),
+    (
+        "padding-inline",
+        &["padding-inline-start", "padding-inline-end"],
+    ),
+    ("place-content", &["align-content", "justify-content"]),
+    ("place-items", &["align-items", "justify-items"]),
+    ("place-self", &["align-self", "justify-self"]),
+    (
+        "scroll-margin",
+        &[
+            "scroll-margin-top",
+            "scroll-margin-right",
+            "scroll-margin-bottom",
+            "scroll-margin-left",
+        ],
+    ),
+    (
+        "scroll-margin-block",
+        &["scroll-margin-block-start", "scroll-margin-block-end"],
+    ),
+    (
+        "scroll-margin-inline",
+        &["scroll-margin-inline-start", "scroll-margin-inline-end"],
+    ),
+    (
+        "scroll-padding",
+        &[
+            "scroll-padding-top",
+            "scroll-padding-right",
+            "scroll-padding-bottom",
+            "scroll-padding-left",
+        ],
+    ),
+    (
+        "scroll-padding-block",
+        &["scroll-padding-block-start", "scroll-padding-block-end"],
+    ),
+    (
+        "scroll-padding-inline",
+        &["scroll-padding-inline-start", "scroll-padding-inline-end"],
+    ),
+    (
+        "scroll-timeline",
+        &["scroll-timeline-name", "scroll-timeline-axis"],
+    ),
+    (
+        "text-decoration",
+        &[
+            "text-decoration-line",
+            "text-decoration-style",
+            "text-decoration-color",
+            "text-decoration-thickness",
+        ],
+    ),
+    (
+        "text-emphasis",
+        &["text-emphasis-style", "text-emphasis-color"],
+    ),
+    ("text-wrap", &["text-wrap-mode", "text-wrap-style"]),
+    (
+        "transition",
+        &[
+            "transition-property",
+            "transition-duration",
+            "transition-timing-function",
+            "transition-delay",
+        ],
+    ),
+    ("white-space", &["white-space-collapse", "text-wrap-mode"]),
+];
+
+/// Returns every property which is set when the shorthand `name` is used.
+///
+/// The list is transitive: for `border` it contains both `border-top` and
+/// `border-top-width`. Matching is ASCII case-insensitive. Returns `None` if
+/// `name` is not a shorthand.
+pub fn longhands_of(name: &str) -> Option<&'static [&'static str]> {
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        return longhands_of_lowercase(&name.to_ascii_lowercase());
+    }
+
+    longhands_of_lowercase(name)
+}
+
+/// Returns `true` if using `shorthand` overrides `longhand`.
+pub fn is_longhand_of(shorthand: &str, longhand: &str) -> bool {
+    match longhands_of(shorthand) {
+        Some(longhands) => longhands.iter().any(|l| l.eq_ignore_ascii_case(longhand)),
+        None => false,
+    }
+}
+
+fn longhands_of_lowercase(name: &str) -> Option<&'static [&'static str]> {
+    SHORTHANDS
+        .binary_search_by_key(&name, |&(shorthand, _)| shorthand)
+        .ok()
+        .map(|idx| SHORTHANDS[idx].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::is_known_property;
+
+    #[test]
+    fn table_is_sorted() {
+        for w in SHORTHANDS.windows(2) {
+            assert!(
+                w[0].0 < w[1].0,
+                "`{}` should come after `{}`",
+                w[0].0,
+                w[1].0
+            );
+        }
+    }
+
+    #[test]
+    fn longhands() {
+        assert!(is_longhand_of("background", "background-color"));
+        assert!(is_longhand_of("BORDER", "border-top-width"));
+        assert!(is_longhand_of("grid-area", "grid-row-end"));
+        assert!(is_longhand_of("text-wrap", "text-wrap-mode"));
+        assert!(is_longhand_of("white-space", "text-wrap-mode"));
+        assert!(is_longhand_of("marker", "marker-end"));
+        assert!(is_longhand_of("font-synthesis", "font-synthesis-weight"));
+        assert!(!is_longhand_of("margin", "padding-top"));
+        assert!(!is_longhand_of("color", "color"));
+    }
+
+    #[test]
+    fn longhands_are_known_properties() {
+        for &(shorthand, longhands) in SHORTHANDS {
+            assert!(is_known_property(shorthand), "{}", shorthand);
+
+            for longhand in longhands {
+                assert!(is_known_property(longhand), "{}", longhand);
+            }
+        }
+    }
+}
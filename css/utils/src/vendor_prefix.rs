@@ -0,0 +1,302 @@
+use std::borrow::Cow;
+
+/// A vendor prefix like `-webkit-`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VendorPrefix {
+    /// `-webkit-`
+    Webkit,
+    /// `-moz-`
+    Moz,
+    /// `-ms-`
+    Ms,
+    /// `-o-`
+    O,
+}
+
+impl VendorPrefix {
+    pub const ALL: [VendorPrefix; 4] = [
+        VendorPrefix::Webkit,
+        VendorPrefix::Moz,
+        VendorPrefix::Ms,
+        VendorPrefix::O,
+    ];
+
+    /// Returns the prefix including both dashes, e.g. `-webkit-`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VendorPrefix::Webkit => "-webkit-",
+            VendorPrefix::Moz => "-moz-",
+            VendorPrefix::Ms => "-ms-",
+            VendorPrefix::O => "-o-",
+        }
+    }
+}
+
+/// Splits `name` into its vendor prefix and the unprefixed rest.
+///
+/// Prefixes are matched ASCII case-insensitively. Works for property names,
+/// keywords, function names, at-rule names (without `@`) and pseudo
+/// selectors (without `:`).
+///
+/// ```
+/// use swc_css_utils::vendor_prefix::{split_vendor_prefix, VendorPrefix};
+///
+/// assert_eq!(
+///     split_vendor_prefix("-webkit-transition"),
+///     (Some(VendorPrefix::Webkit), "transition")
+/// );
+/// assert_eq!(split_vendor_prefix("transition"), (None, "transition"));
+/// ```
+pub fn split_vendor_prefix(name: &str) -> (Option<VendorPrefix>, &str) {
+    for &prefix in VendorPrefix::ALL.iter() {
+        let s = prefix.as_str();
+
+        if name.len() > s.len()
+            && name.is_char_boundary(s.len())
+            && name[..s.len()].eq_ignore_ascii_case(s)
+        {
+            return (Some(prefix), &name[s.len()..]);
+        }
+    }
+
+    (None, name)
+}
+
+/// Returns the vendor prefix of `name`, if any.
+pub fn vendor_prefix(name: &str) -> Option<VendorPrefix> {
+    split_vendor_prefix(name).0
+}
+
+/// Returns `name` without its vendor prefix.
+pub fn unprefixed(name: &str) -> &str {
+    split_vendor_prefix(name).1
+}
+
+/// Returns the standard property which `name` is a vendor specific version
+/// of.
+///
+/// Besides stripping the prefix, this resolves legacy aliases emitted by
+/// prefixers, like `-ms-flex-positive` for `flex-grow`. The result is always
+/// lowercase, except for custom properties (`--foo`) which are case-sensitive
+/// and returned as is.
+///
+/// ```
+/// use swc_css_utils::vendor_prefix::canonical_property_name;
+///
+/// assert_eq!(canonical_property_name("-webkit-transition"), "transition");
+/// assert_eq!(canonical_property_name("-webkit-box-pack"), "justify-content");
+/// assert_eq!(canonical_property_name("color"), "color");
+/// ```
+pub fn canonical_property_name(name: &str) -> Cow<'_, str> {
+    if name.starts_with("--") {
+        return Cow::Borrowed(name);
+    }
+
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        let name = name.to_ascii_lowercase();
+        return Cow::Owned(canonical_property_name_lowercase(&name).to_string());
+    }
+
+    Cow::Borrowed(canonical_property_name_lowercase(name))
+}
+
+fn canonical_property_name_lowercase(name: &str) -> &str {
+    legacy_property_alias(name).unwrap_or_else(|| unprefixed(name))
+}
+
+fn legacy_property_alias(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "-webkit-box-flex" | "-ms-flex-positive" => "flex-grow",
+        "-ms-flex-negative" => "flex-shrink",
+        "-ms-flex-preferred-size" => "flex-basis",
+        "-ms-flex-item-align" => "align-self",
+        "-ms-flex-line-pack" => "align-content",
+        "-webkit-box-align" | "-ms-flex-align" => "align-items",
+        "-webkit-box-pack" | "-ms-flex-pack" => "justify-content",
+        "-webkit-box-ordinal-group" | "-ms-flex-order" => "order",
+        "-webkit-margin-start" => "margin-inline-start",
+        "-webkit-margin-end" => "margin-inline-end",
+        "-webkit-padding-start" => "padding-inline-start",
+        "-webkit-padding-end" => "padding-inline-end",
+        _ => return None,
+    })
+}
+
+const WEBKIT: &[VendorPrefix] = &[VendorPrefix::Webkit];
+const WEBKIT_MOZ: &[VendorPrefix] = &[VendorPrefix::Webkit, VendorPrefix::Moz];
+const WEBKIT_MS: &[VendorPrefix] = &[VendorPrefix::Webkit, VendorPrefix::Ms];
+const WEBKIT_MOZ_MS: &[VendorPrefix] = &[VendorPrefix::Webkit, VendorPrefix::Moz, VendorPrefix::Ms];
+const ALL_PREFIXES: &[VendorPrefix] = &VendorPrefix::ALL;
+
+/// Standard properties and the prefixes they were shipped with, sorted so
+/// they can be binary searched.
+static PROPERTY_PREFIXES: &[(&str, &[VendorPrefix])] = &[
+    ("align-content", WEBKIT),
+    ("align-items", WEBKIT),
+    ("align-self", WEBKIT),
+    ("animation", ALL_PREFIXES),
+    ("animation-delay", ALL_PREFIXES),
+    ("animation-direction", ALL_PREFIXES),
+    ("animation-duration", ALL_PREFIXES),
+    ("animation-fill-mode", ALL_PREFIXES),
+    ("animation-iteration-count", ALL_PREFIXES),
+    ("animation-name", ALL_PREFIXES),
+    ("animation-play-state", ALL_PREFIXES),
+    ("animation-timing-function", ALL_PREFIXES),
+    ("appearance", WEBKIT_MOZ_MS),
+    ("backface-visibility", WEBKIT),
+    ("background-clip", WEBKIT),
+    ("box-decoration-break", WEBKIT),
+    ("box-sizing", WEBKIT_MOZ),
+    ("clip-path", WEBKIT),
+    ("column-count", WEBKIT_MOZ),
+    ("column-fill", WEBKIT_MOZ),
+    ("column-gap", WEBKIT_MOZ),
+    ("column-rule", WEBKIT_MOZ),
+    ("column-rule-color", WEBKIT_MOZ),
+    ("column-rule-style", WEBKIT_MOZ),
+    ("column-rule-width", WEBKIT_MOZ),
+    ("column-span", WEBKIT_MOZ),
+    ("column-width", WEBKIT_MOZ),
+    ("columns", WEBKIT_MOZ),
+    ("filter", WEBKIT),
+    ("flex", WEBKIT_MS),
+    ("flex-basis", WEBKIT),
+    ("flex-direction", WEBKIT_MS),
+    ("flex-flow", WEBKIT_MS),
+    ("flex-grow", WEBKIT),
+    ("flex-shrink", WEBKIT),
+    ("flex-wrap", WEBKIT_MS),
+    ("hyphens", WEBKIT_MOZ_MS),
+    ("justify-content", WEBKIT),
+    ("mask", WEBKIT),
+    ("mask-clip", WEBKIT),
+    ("mask-composite", WEBKIT),
+    ("mask-image", WEBKIT),
+    ("mask-mode", WEBKIT),
+    ("mask-origin", WEBKIT),
+    ("mask-position", WEBKIT),
+    ("mask-repeat", WEBKIT),
+    ("mask-size", WEBKIT),
+    ("order", WEBKIT),
+    ("perspective", WEBKIT),
+    ("perspective-origin", WEBKIT),
+    ("print-color-adjust", WEBKIT),
+    ("tab-size", WEBKIT_MOZ),
+    ("text-decoration", WEBKIT),
+    ("text-emphasis", WEBKIT),
+    ("text-size-adjust", WEBKIT_MOZ_MS),
+    ("transform", WEBKIT_MOZ_MS),
+    ("transform-origin", ALL_PREFIXES),
+    ("transform-style", WEBKIT),
+    ("transition", ALL_PREFIXES),
+    ("transition-delay", ALL_PREFIXES),
+    ("transition-duration", ALL_PREFIXES),
+    ("transition-property", ALL_PREFIXES),
+    ("transition-timing-function", ALL_PREFIXES),
+    ("user-select", WEBKIT_MOZ_MS),
+    ("writing-mode", WEBKIT_MS),
+];
+
+/// Returns the vendor prefixes with which the standard property `name` was
+/// shipped by at least one browser.
+///
+/// Matching is ASCII case-insensitive. Legacy aliases (see
+/// [canonical_property_name]) are not considered, and the list is empty for
+/// properties which never needed a prefix.
+pub fn property_prefixes(name: &str) -> &'static [VendorPrefix] {
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        return property_prefixes_lowercase(&name.to_ascii_lowercase());
+    }
+
+    property_prefixes_lowercase(name)
+}
+
+fn property_prefixes_lowercase(name: &str) -> &'static [VendorPrefix] {
+    PROPERTY_PREFIXES
+        .binary_search_by_key(&name, |&(property, _)| property)
+        .map_or(&[], |idx| PROPERTY_PREFIXES[idx].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted() {
+        for w in PROPERTY_PREFIXES.windows(2) {
+            assert!(
+                w[0].0 < w[1].0,
+                "`{}` should come after `{}`",
+                w[0].0,
+                w[1].0
+            );
+        }
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            split_vendor_prefix("-MOZ-appearance"),
+            (Some(VendorPrefix::Moz), "appearance")
+        );
+        assert_eq!(split_vendor_prefix("-webkit-"), (None, "-webkit-"));
+        assert_eq!(split_vendor_prefix("--webkit-foo"), (None, "--webkit-foo"));
+    }
+
+    #[test]
+    fn canonical_names() {
+        assert_eq!(canonical_property_name("-ms-flex-positive"), "flex-grow");
+        assert_eq!(canonical_property_name("-webkit-box-flex"), "flex-grow");
+        assert_eq!(canonical_property_name("-MS-FLEX-ALIGN"), "align-items");
+        assert_eq!(
+            canonical_property_name("-ms-flex-line-pack"),
+            "align-content"
+        );
+        assert_eq!(canonical_property_name("-Moz-Transform"), "transform");
+        assert_eq!(canonical_property_name("Color"), "color");
+        assert_eq!(canonical_property_name("--Custom"), "--Custom");
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(
+            property_prefixes("user-select"),
+            &[VendorPrefix::Webkit, VendorPrefix::Moz, VendorPrefix::Ms]
+        );
+        assert_eq!(property_prefixes("FLEX-GROW"), &[VendorPrefix::Webkit]);
+        assert_eq!(property_prefixes("transition"), &VendorPrefix::ALL);
+        assert!(property_prefixes("color").is_empty());
+    }
+
+    #[test]
+    fn prefixed_properties_are_known_properties() {
+        use crate::properties::is_known_property;
+
+        for &(name, _) in PROPERTY_PREFIXES {
+            assert!(is_known_property(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn canonical_names_are_known_properties() {
+        use crate::properties::is_known_property;
+
+        for name in &[
+            "-webkit-box-flex",
+            "-ms-flex-negative",
+            "-ms-flex-preferred-size",
+            "-ms-flex-item-align",
+            "-webkit-box-pack",
+            "-ms-flex-order",
+            "-webkit-margin-start",
+            "-webkit-padding-end",
+        ] {
+            assert!(
+                is_known_property(&canonical_property_name(name)),
+                "{}",
+                name
+            );
+        }
+    }
+}